@@ -1,7 +1,7 @@
 pub mod gfarch {
     use bpe_rs::bpe;
     use nintendo_lz;
-    use byteorder::{ByteOrder, LittleEndian};
+    use byteorder::{BigEndian, ByteOrder, LittleEndian};
     use thiserror;
 
     #[derive(thiserror::Error, Debug)]
@@ -17,7 +17,52 @@ pub mod gfarch {
         UnsupportedCompressionTypeError(u32),
 
         #[error("Failed to decompress LZ10")]
-        LZ10DecompressError
+        LZ10DecompressError,
+
+        #[error("Failed to decompress BPE")]
+        BPEDecompressError,
+
+        #[error("Failed to decompress Yaz0: {0}")]
+        Yaz0DecompressError(String),
+
+        #[error("Failed to decompress Yay0: {0}")]
+        Yay0DecompressError(String),
+
+        #[error("Checksum mismatch for file '{filename}': expected {expected:#010X}, found {found:#010X}")]
+        IntegrityError {
+            filename: String,
+            expected: u32,
+            found: u32
+        },
+
+        #[error("File '{filename}' range {offset:#X}..{end:#X} is out of bounds for a decompressed chunk of size {chunk_len:#X}", end = offset + size)]
+        OutOfBoundsError {
+            filename: String,
+            offset: usize,
+            size: usize,
+            chunk_len: usize
+        },
+
+        #[error("Archive is truncated: needed {needed} byte(s) at offset {offset:#X}, but the input ends there")]
+        TruncatedArchive {
+            offset: usize,
+            needed: usize
+        },
+
+        #[error("File '{filename}' has decompressed_offset {decompressed_offset:#X}, which is before the GFCP payload start {gfcp_offset:#X}")]
+        InvalidEntryOffset {
+            filename: String,
+            decompressed_offset: usize,
+            gfcp_offset: usize
+        },
+
+        #[error(transparent)]
+        IoError(#[from] std::io::Error),
+
+        #[error("Filename at offset {offset:#X} is not valid UTF-8")]
+        InvalidFilename {
+            offset: usize
+        }
     }
 
     /// Allows the user to specify a custom GFCP offset.
@@ -38,15 +83,28 @@ pub mod gfarch {
     /// The compression type of a GfArch archive.
     pub enum CompressionType {
         BPE,
-        LZ10
+        LZ10,
+        Yaz0,
+        Yay0
     }
 
     struct FileEntry {
+        checksum: u32,
         name_offset: usize,
         decompressed_size: usize,
         decompressed_offset: usize,
     }
 
+    /// The result of parsing a GfArch archive's header and decompressing its
+    /// GFCP payload, before individual files are sliced out.
+    struct ParsedArchive {
+        entries: Vec<FileEntry>,
+        filenames: Vec<String>,
+        gfcp_offset: usize,
+        decompressed_chunk: Vec<u8>,
+    }
+
+    #[derive(Debug)]
     pub struct FileContents {
         pub contents: Vec<u8>,
         pub filename: String
@@ -57,11 +115,13 @@ pub mod gfarch {
         fn from_bytes(input: &[u8]) -> Self {
             assert_eq!(0x10, input.len());
 
+            let checksum = LittleEndian::read_u32(&input[0..4]);
             let name_offset = (LittleEndian::read_u32(&input[4..8]) & 0x00FFFFFF) as usize;
             let decompressed_size = LittleEndian::read_u32(&input[8..0xC]) as usize;
             let decompressed_offset = LittleEndian::read_u32(&input[0xC..0x10]) as usize;
 
             Self {
+                checksum,
                 name_offset,
                 decompressed_size,
                 decompressed_offset
@@ -86,18 +146,371 @@ pub mod gfarch {
         result
     }
 
-    fn read_string(input: &[u8], offset: usize) -> String {
-        let mut result = String::new();
+    /// Compresses `input` into a raw LZ77 type-0x10 stream, matching the
+    /// format `nintendo_lz::decompress_arr` expects after its 4-byte header.
+    ///
+    /// ### Parameters
+    /// `input`: The data to compress.
+    ///
+    /// ### Returns
+    /// The compressed bytes, without the `nintendo_lz` magic/size header.
+    fn compress_lz10(input: &[u8]) -> Vec<u8> {
+        const MIN_MATCH_LEN: usize = 3;
+        const MAX_MATCH_LEN: usize = 18;
+        const MAX_DISPLACEMENT: usize = 4096;
+
+        let mut output = Vec::new();
+        let mut pos = 0;
+
+        while pos < input.len() {
+            let flag_pos = output.len();
+            output.push(0);
+            let mut flag_byte = 0u8;
+
+            for bit in 0..8 {
+                if pos >= input.len() {
+                    break;
+                }
 
-        for &byte in &input[offset..] {
-            if byte == 0 {
-                break;
+                let window_start = pos.saturating_sub(MAX_DISPLACEMENT);
+                let max_len = (input.len() - pos).min(MAX_MATCH_LEN);
+                let mut best_len = 0usize;
+                let mut best_disp = 0usize;
+
+                if max_len >= MIN_MATCH_LEN {
+                    for start in window_start..pos {
+                        let disp = pos - start;
+                        let mut len = 0;
+
+                        while len < max_len && input[start + len] == input[pos + len] {
+                            len += 1;
+                        }
+
+                        if len > best_len {
+                            best_len = len;
+                            best_disp = disp;
+                        }
+                    }
+                }
+
+                if best_len >= MIN_MATCH_LEN {
+                    flag_byte |= 1 << (7 - bit);
+
+                    let length = (best_len - MIN_MATCH_LEN) as u8;
+                    let displacement = (best_disp - 1) as u16;
+
+                    output.push((length << 4) | ((displacement >> 8) as u8 & 0xF));
+                    output.push((displacement & 0xFF) as u8);
+
+                    pos += best_len;
+                } else {
+                    output.push(input[pos]);
+                    pos += 1;
+                }
             }
 
-            result.push(byte as char);
+            output[flag_pos] = flag_byte;
         }
 
-        result        
+        output
+    }
+
+    /// Reads a single byte at `offset`, or a `GfArchError::TruncatedArchive`
+    /// if the input ends before then.
+    fn byte_at(input: &[u8], offset: usize) -> Result<u8, GfArchError> {
+        input.get(offset).copied().ok_or(GfArchError::TruncatedArchive { offset, needed: 1 })
+    }
+
+    /// Decompresses a raw Yaz0 flag/literal/back-reference bitstream (i.e.
+    /// without the `"Yaz0"` magic/size/reserved header); `decompressed_size`
+    /// comes from the GFCP header instead, which already stores it.
+    fn decompress_yaz0(input: &[u8], decompressed_size: usize) -> Result<Vec<u8>, GfArchError> {
+        let mut output = Vec::with_capacity(decompressed_size);
+        let mut pos = 0;
+        let mut bit_mask = 0u8;
+        let mut flag_byte = 0u8;
+
+        while output.len() < decompressed_size {
+            if bit_mask == 0 {
+                flag_byte = byte_at(input, pos)?;
+                pos += 1;
+                bit_mask = 0x80;
+            }
+
+            if flag_byte & bit_mask != 0 {
+                output.push(byte_at(input, pos)?);
+                pos += 1;
+            } else {
+                let b0 = byte_at(input, pos)?;
+                let b1 = byte_at(input, pos + 1)?;
+                pos += 2;
+
+                let high_nibble = b0 >> 4;
+                let length = if high_nibble == 0 {
+                    let extra = byte_at(input, pos)?;
+                    pos += 1;
+                    extra as usize + 0x12
+                } else {
+                    high_nibble as usize + 2
+                };
+
+                let displacement = (((b0 & 0xF) as usize) << 8 | b1 as usize) + 1;
+                let start = output.len().checked_sub(displacement).ok_or_else(|| {
+                    GfArchError::Yaz0DecompressError(format!(
+                        "back-reference displacement {displacement} exceeds decompressed length {}",
+                        output.len()
+                    ))
+                })?;
+
+                for i in 0..length {
+                    let byte = output[start + i];
+                    output.push(byte);
+                }
+            }
+
+            bit_mask >>= 1;
+        }
+
+        Ok(output)
+    }
+
+    /// Compresses `input` into a raw Yaz0 flag/literal/back-reference
+    /// bitstream, without the `"Yaz0"` magic/size/reserved header, since the
+    /// decompressed size is already stored in the GFCP header separately.
+    fn compress_yaz0(input: &[u8]) -> Vec<u8> {
+        const MIN_MATCH_LEN: usize = 3;
+        const MAX_MATCH_LEN: usize = 0x111;
+        const MAX_DISPLACEMENT: usize = 4096;
+
+        let mut body = Vec::new();
+        let mut pos = 0;
+
+        while pos < input.len() {
+            let flag_pos = body.len();
+            body.push(0);
+            let mut flag_byte = 0u8;
+
+            for bit in 0..8 {
+                if pos >= input.len() {
+                    break;
+                }
+
+                let window_start = pos.saturating_sub(MAX_DISPLACEMENT);
+                let max_len = (input.len() - pos).min(MAX_MATCH_LEN);
+                let mut best_len = 0usize;
+                let mut best_disp = 0usize;
+
+                if max_len >= MIN_MATCH_LEN {
+                    for start in window_start..pos {
+                        let disp = pos - start;
+                        let mut len = 0;
+
+                        while len < max_len && input[start + len] == input[pos + len] {
+                            len += 1;
+                        }
+
+                        if len > best_len {
+                            best_len = len;
+                            best_disp = disp;
+                        }
+                    }
+                }
+
+                if best_len >= MIN_MATCH_LEN {
+                    let displacement = (best_disp - 1) as u16;
+
+                    if best_len <= 17 {
+                        let nibble = (best_len - 2) as u8;
+                        body.push((nibble << 4) | ((displacement >> 8) as u8 & 0xF));
+                        body.push((displacement & 0xFF) as u8);
+                    } else {
+                        body.push((displacement >> 8) as u8 & 0xF);
+                        body.push((displacement & 0xFF) as u8);
+                        body.push((best_len - 0x12) as u8);
+                    }
+
+                    pos += best_len;
+                } else {
+                    flag_byte |= 1 << (7 - bit);
+                    body.push(input[pos]);
+                    pos += 1;
+                }
+            }
+
+            body[flag_pos] = flag_byte;
+        }
+
+        body
+    }
+
+    /// Decompresses a raw Yay0 stream: an 8-byte mini-header (link table
+    /// offset and chunk offset, both relative to the start of this stream
+    /// and both big-endian `u32`s) followed by the command bitstream, the
+    /// back-reference link table, and the shared literal/extra-length chunk
+    /// data. The `"Yay0"` magic and decompressed size of a native Yay0
+    /// container are omitted, since the GFCP header already stores the
+    /// decompressed size and the magic carries no information here.
+    fn decompress_yay0(input: &[u8], decompressed_size: usize) -> Result<Vec<u8>, GfArchError> {
+        let link_table_offset = BigEndian::read_u32(checked_slice(input, 0, 4)?) as usize;
+        let chunk_offset = BigEndian::read_u32(checked_slice(input, 4, 4)?) as usize;
+
+        let mut output = Vec::with_capacity(decompressed_size);
+        let mut cmd_pos = 8;
+        let mut link_pos = link_table_offset;
+        let mut chunk_pos = chunk_offset;
+        let mut bit_mask = 0u8;
+        let mut cmd_byte = 0u8;
+
+        while output.len() < decompressed_size {
+            if bit_mask == 0 {
+                cmd_byte = byte_at(input, cmd_pos)?;
+                cmd_pos += 1;
+                bit_mask = 0x80;
+            }
+
+            if cmd_byte & bit_mask != 0 {
+                output.push(byte_at(input, chunk_pos)?);
+                chunk_pos += 1;
+            } else {
+                let b0 = byte_at(input, link_pos)?;
+                let b1 = byte_at(input, link_pos + 1)?;
+                link_pos += 2;
+
+                let high_nibble = b0 >> 4;
+                let length = if high_nibble == 0 {
+                    let extra = byte_at(input, chunk_pos)?;
+                    chunk_pos += 1;
+                    extra as usize + 0x12
+                } else {
+                    high_nibble as usize + 2
+                };
+
+                let displacement = (((b0 & 0xF) as usize) << 8 | b1 as usize) + 1;
+                let start = output.len().checked_sub(displacement).ok_or_else(|| {
+                    GfArchError::Yay0DecompressError(format!(
+                        "back-reference displacement {displacement} exceeds decompressed length {}",
+                        output.len()
+                    ))
+                })?;
+
+                for i in 0..length {
+                    let byte = output[start + i];
+                    output.push(byte);
+                }
+            }
+
+            bit_mask >>= 1;
+        }
+
+        Ok(output)
+    }
+
+    /// Compresses `input` into a raw Yay0 stream: an 8-byte mini-header
+    /// (link table offset and chunk offset) followed by the command
+    /// bitstream, the back-reference link table, and the shared
+    /// literal/extra-length chunk data, each laid out back-to-back. Unlike
+    /// a native Yay0 container, the `"Yay0"` magic and decompressed size
+    /// are omitted, since the GFCP header already stores the decompressed
+    /// size; the link/chunk offsets are kept because, unlike the size,
+    /// they aren't duplicated anywhere else in the archive.
+    fn compress_yay0(input: &[u8]) -> Vec<u8> {
+        const MIN_MATCH_LEN: usize = 3;
+        const MAX_MATCH_LEN: usize = 0x111;
+        const MAX_DISPLACEMENT: usize = 4096;
+
+        let mut commands = Vec::new();
+        let mut links = Vec::new();
+        let mut chunk = Vec::new();
+
+        let mut cmd_byte = 0u8;
+        let mut bit_mask = 0x80u8;
+        let mut pos = 0;
+
+        while pos < input.len() {
+            let window_start = pos.saturating_sub(MAX_DISPLACEMENT);
+            let max_len = (input.len() - pos).min(MAX_MATCH_LEN);
+            let mut best_len = 0usize;
+            let mut best_disp = 0usize;
+
+            if max_len >= MIN_MATCH_LEN {
+                for start in window_start..pos {
+                    let disp = pos - start;
+                    let mut len = 0;
+
+                    while len < max_len && input[start + len] == input[pos + len] {
+                        len += 1;
+                    }
+
+                    if len > best_len {
+                        best_len = len;
+                        best_disp = disp;
+                    }
+                }
+            }
+
+            if best_len >= MIN_MATCH_LEN {
+                let displacement = (best_disp - 1) as u16;
+
+                if best_len <= 17 {
+                    let nibble = (best_len - 2) as u8;
+                    links.push((nibble << 4) | ((displacement >> 8) as u8 & 0xF));
+                    links.push((displacement & 0xFF) as u8);
+                } else {
+                    links.push((displacement >> 8) as u8 & 0xF);
+                    links.push((displacement & 0xFF) as u8);
+                    chunk.push((best_len - 0x12) as u8);
+                }
+
+                pos += best_len;
+            } else {
+                cmd_byte |= bit_mask;
+                chunk.push(input[pos]);
+                pos += 1;
+            }
+
+            bit_mask >>= 1;
+            if bit_mask == 0 {
+                commands.push(cmd_byte);
+                cmd_byte = 0;
+                bit_mask = 0x80;
+            }
+        }
+
+        if bit_mask != 0x80 {
+            commands.push(cmd_byte);
+        }
+
+        let link_table_offset = 8 + commands.len();
+        let chunk_offset = link_table_offset + links.len();
+
+        let mut output = Vec::with_capacity(chunk_offset + chunk.len());
+
+        let mut buf = [0u8; 4];
+        BigEndian::write_u32(&mut buf, link_table_offset as u32);
+        output.extend_from_slice(&buf);
+        BigEndian::write_u32(&mut buf, chunk_offset as u32);
+        output.extend_from_slice(&buf);
+
+        output.extend_from_slice(&commands);
+        output.extend_from_slice(&links);
+        output.extend_from_slice(&chunk);
+
+        output
+    }
+
+    /// Returns `input[offset..offset + needed]`, or a
+    /// `GfArchError::TruncatedArchive` if the input ends before then.
+    fn checked_slice(input: &[u8], offset: usize, needed: usize) -> Result<&[u8], GfArchError> {
+        input.get(offset..offset + needed).ok_or(GfArchError::TruncatedArchive { offset, needed })
+    }
+
+    /// Reads a null-terminated, UTF-8 encoded filename starting at `offset`.
+    fn read_string(input: &[u8], offset: usize) -> Result<String, GfArchError> {
+        let region = checked_slice(input, offset, input.len().saturating_sub(offset))?;
+        let end = region.iter().position(|&byte| byte == 0).unwrap_or(region.len());
+
+        String::from_utf8(region[..end].to_vec())
+            .map_err(|_| GfArchError::InvalidFilename { offset })
     }
 
     /// Extracts the contents of a GfArch archive.
@@ -108,62 +521,103 @@ pub mod gfarch {
     /// ### Returns
     /// A `Vec<FileContents>`, containing the contents of the archive.
     pub fn extract(input: &[u8]) -> Result<Vec<FileContents>, GfArchError> {
-        if &input[..4] != b"GFAC" {
+        extract_impl(input, false)
+    }
+
+    /// Extracts the contents of a GfArch archive, verifying each file's
+    /// checksum and decompressed range before returning it.
+    ///
+    /// ### Parameters
+    /// `input`: The archive contents to be extracted.
+    ///
+    /// ### Returns
+    /// A `Vec<FileContents>`, containing the contents of the archive, or a
+    /// `GfArchError::IntegrityError`/`GfArchError::OutOfBoundsError` if a
+    /// file entry's checksum doesn't match its filename or its decompressed
+    /// range falls outside the decompressed chunk.
+    pub fn extract_verified(input: &[u8]) -> Result<Vec<FileContents>, GfArchError> {
+        extract_impl(input, true)
+    }
+
+    /// Parses a GfArch archive's header, file entries, filenames, and
+    /// decompresses its GFCP payload, without slicing out individual files.
+    /// Shared by the in-memory and streaming extraction entry points.
+    fn parse_archive(input: &[u8]) -> Result<ParsedArchive, GfArchError> {
+        if checked_slice(input, 0, 4)? != b"GFAC" {
             return Err(GfArchError::ArchiveHeaderError);
         }
 
-        let file_count = LittleEndian::read_u32(&input[0x2C..0x30]);
+        let file_count = LittleEndian::read_u32(checked_slice(input, 0x2C, 4)?);
         let mut entries = Vec::new();
         let mut filenames = Vec::<String>::new();
 
         // read file entries
-        
+
+        let entries_region = checked_slice(input, 0x30, file_count as usize * 0x10)?;
+
         entries.extend(
-            input[0x30..]
+            entries_region
             .chunks(0x10)
             .take(file_count as usize)
             .map(FileEntry::from_bytes)
         );
 
         // read filenames
-        
-        filenames.extend(
-            entries.iter().map(|entry|
-                read_string(input, entry.name_offset)
-            )
-        );
+
+        for entry in entries.iter() {
+            filenames.push(read_string(input, entry.name_offset)?);
+        }
 
         // read compression header
 
-        let gfcp_offset = LittleEndian::read_u32(&input[0x14..0x18]) as usize;
+        let gfcp_offset = LittleEndian::read_u32(checked_slice(input, 0x14, 4)?) as usize;
 
-        if &input[gfcp_offset..gfcp_offset + 4] != b"GFCP" {
+        if checked_slice(input, gfcp_offset, 4)? != b"GFCP" {
             return Err(GfArchError::CompressionHeaderError);
         }
 
         // decompress files
 
-        let raw_compression_type = LittleEndian::read_u32(&input[gfcp_offset + 0x8..gfcp_offset + 0xC]); 
+        let raw_compression_type = LittleEndian::read_u32(checked_slice(input, gfcp_offset + 0x8, 4)?);
         let compression_type = match raw_compression_type {
             1 => CompressionType::BPE,
             3 => CompressionType::LZ10,
+            4 => CompressionType::Yaz0,
+            5 => CompressionType::Yay0,
             _ => {
                 return Err(GfArchError::UnsupportedCompressionTypeError(raw_compression_type))
             }
         };
 
+        // every compression type's payload starts after the 0x14-byte GFCP header
+        checked_slice(input, gfcp_offset, 0x14)?;
+        let payload = &input[gfcp_offset + 0x14..];
 
         let decompressed_chunk = match compression_type {
-            CompressionType::BPE => bpe::decode(&input[gfcp_offset + 0x14..], bpe::DEFAULT_STACK_SIZE),
+            CompressionType::BPE => {
+                // bpe_rs::bpe::decode indexes its internal tables directly and
+                // panics on truncated/corrupt input instead of returning a
+                // Result, so catch that panic and surface it as a typed error
+                // like every other decompression path here.
+                let result = std::panic::catch_unwind(
+                    std::panic::AssertUnwindSafe(|| bpe::decode(payload, bpe::DEFAULT_STACK_SIZE))
+                );
+
+                if let Ok(decompressed) = result {
+                    decompressed
+                } else {
+                    return Err(GfArchError::BPEDecompressError);
+                }
+            }
             CompressionType::LZ10 => {
                 let decompressed_size = LittleEndian::read_u32(
-                    &input[gfcp_offset + 0xC..gfcp_offset + 0x10]
+                    checked_slice(input, gfcp_offset + 0xC, 4)?
                 );
 
                 // construct a header for nintendo_lz
                 let mut lz_chunk = vec![0x10];
                 lz_chunk.extend_from_slice(&decompressed_size.to_le_bytes()[..3]);
-                lz_chunk.extend_from_slice(&input[gfcp_offset + 0x14..]);
+                lz_chunk.extend_from_slice(payload);
 
 
                 let result = nintendo_lz::decompress_arr(&lz_chunk);
@@ -183,22 +637,143 @@ pub mod gfarch {
                 //     decompressed_size as usize
                 // )
             }
+
+            CompressionType::Yaz0 => {
+                let decompressed_size = LittleEndian::read_u32(
+                    checked_slice(input, gfcp_offset + 0xC, 4)?
+                ) as usize;
+
+                decompress_yaz0(payload, decompressed_size)?
+            }
+
+            CompressionType::Yay0 => {
+                let decompressed_size = LittleEndian::read_u32(
+                    checked_slice(input, gfcp_offset + 0xC, 4)?
+                ) as usize;
+
+                decompress_yay0(payload, decompressed_size)?
+            }
         };
 
-        let files: Vec<FileContents> = (0..file_count as usize)
-            .map(|i| {
-                let offset = entries[i].decompressed_offset - gfcp_offset;
-                let size = entries[i].decompressed_size;
+        Ok(ParsedArchive { entries, filenames, gfcp_offset, decompressed_chunk })
+    }
 
-                FileContents {
-                    contents: decompressed_chunk[offset..offset + size].to_vec(),
-                    filename: filenames[i].clone(),
-                }
-            }).collect();
+    /// Resolves an entry's byte range within the decompressed chunk,
+    /// verifying its checksum when `verify` is set and always bounds-checking
+    /// the range against the chunk.
+    fn resolve_entry_range(
+        entry: &FileEntry,
+        filename: &str,
+        gfcp_offset: usize,
+        decompressed_chunk: &[u8],
+        verify: bool
+    ) -> Result<(usize, usize), GfArchError> {
+        if verify {
+            let expected = calculate_checksum(filename);
+            let found = entry.checksum;
+
+            if expected != found {
+                return Err(GfArchError::IntegrityError {
+                    filename: filename.to_string(),
+                    expected,
+                    found
+                });
+            }
+        }
+
+        let offset = entry.decompressed_offset.checked_sub(gfcp_offset)
+            .ok_or_else(|| GfArchError::InvalidEntryOffset {
+                filename: filename.to_string(),
+                decompressed_offset: entry.decompressed_offset,
+                gfcp_offset
+            })?;
+        let size = entry.decompressed_size;
+
+        if offset + size > decompressed_chunk.len() {
+            return Err(GfArchError::OutOfBoundsError {
+                filename: filename.to_string(),
+                offset,
+                size,
+                chunk_len: decompressed_chunk.len()
+            });
+        }
+
+        Ok((offset, size))
+    }
+
+    fn extract_impl(input: &[u8], verify: bool) -> Result<Vec<FileContents>, GfArchError> {
+        let ParsedArchive { entries, filenames, gfcp_offset, decompressed_chunk } = parse_archive(input)?;
+        let mut files = Vec::with_capacity(entries.len());
+
+        for (entry, filename) in entries.iter().zip(filenames) {
+            let (offset, size) = resolve_entry_range(entry, &filename, gfcp_offset, &decompressed_chunk, verify)?;
+
+            files.push(FileContents {
+                contents: decompressed_chunk[offset..offset + size].to_vec(),
+                filename,
+            });
+        }
 
         Ok(files)
     }
 
+    /// Decompresses a GfArch archive once and streams each entry's bytes to
+    /// `sink` without collecting them, in the style of a decompress-into-a-
+    /// callback API.
+    ///
+    /// ### Parameters
+    /// `input`: The archive contents to be extracted.
+    ///
+    /// `sink`: Called once per file with its name and decompressed contents.
+    pub fn extract_each(
+        input: &[u8],
+        mut sink: impl FnMut(&str, &[u8]) -> std::io::Result<()>
+    ) -> Result<(), GfArchError> {
+        let ParsedArchive { entries, filenames, gfcp_offset, decompressed_chunk } = parse_archive(input)?;
+
+        for (entry, filename) in entries.iter().zip(filenames.iter()) {
+            let (offset, size) = resolve_entry_range(entry, filename, gfcp_offset, &decompressed_chunk, false)?;
+            sink(filename, &decompressed_chunk[offset..offset + size])?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes any root or `..` components from an archive filename so it
+    /// can't escape `base` when joined, mirroring how archive CLIs sanitize
+    /// member paths before writing them to disk.
+    fn sanitize_path(base: &std::path::Path, filename: &str) -> std::path::PathBuf {
+        let mut path = base.to_path_buf();
+
+        for component in std::path::Path::new(filename).components() {
+            if let std::path::Component::Normal(part) = component {
+                path.push(part);
+            }
+        }
+
+        path
+    }
+
+    /// Decompresses a GfArch archive once and writes each entry straight to
+    /// `out`, creating parent directories as needed. The decompressed chunk
+    /// is held in memory whole; files are not copied into buffers of their own.
+    ///
+    /// ### Parameters
+    /// `input`: The archive contents to be extracted.
+    ///
+    /// `out`: The directory to extract files into.
+    pub fn extract_to_dir(input: &[u8], out: &std::path::Path) -> Result<(), GfArchError> {
+        extract_each(input, |filename, contents| {
+            let path = sanitize_path(out, filename);
+
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(path, contents)
+        })
+    }
+
 
 
     /// Creates a GfArch archive from given files and filenames.
@@ -276,7 +851,9 @@ pub mod gfarch {
         // compress all data
         let compressed_chunk = match compression_type {
             CompressionType::BPE => bpe::encode(&decompressed_chunk),
-            CompressionType::LZ10 => todo!()
+            CompressionType::LZ10 => compress_lz10(&decompressed_chunk),
+            CompressionType::Yaz0 => compress_yaz0(&decompressed_chunk),
+            CompressionType::Yay0 => compress_yay0(&decompressed_chunk),
         };
 
         let mut file_name_section_length = 0usize;
@@ -301,7 +878,16 @@ pub mod gfarch {
         
         // write archive header
         let mut output = vec![0u8; archive_size];
-        
+
+        // a custom GFCP offset may be too small to fit the file info section
+        // computed above; grow the buffer rather than writing out of bounds
+        let file_info_region_end =
+            0x30 + (file_count * 0x10) + (file_name_section_length + file_count).next_multiple_of(0x10);
+
+        if output.len() < file_info_region_end {
+            output.resize(file_info_region_end, 0);
+        }
+
         // magic
         output[0] = b'G';
         output[1] = b'F';
@@ -404,6 +990,11 @@ pub mod gfarch {
         // magic
 
         let gfcp_offset = gfcp_offset as usize;
+
+        if output.len() < gfcp_offset + 0x14 {
+            output.resize(gfcp_offset + 0x14, 0);
+        }
+
         output[gfcp_offset] = b'G';
         output[gfcp_offset + 1] = b'F';
         output[gfcp_offset + 2] = b'C';
@@ -419,7 +1010,9 @@ pub mod gfarch {
 
             match compression_type {
                 CompressionType::BPE => 1,
-                CompressionType::LZ10 => 3
+                CompressionType::LZ10 => 3,
+                CompressionType::Yaz0 => 4,
+                CompressionType::Yay0 => 5,
             }
         );
 
@@ -462,4 +1055,177 @@ mod tests {
         let checksum = gfarch::calculate_checksum(sample);
         assert_eq!(0xCC91B7B8, checksum.swap_bytes());
     }
+
+    #[test]
+    fn lz10_round_trip() {
+        let contents = vec![b"hello hello hello, gfarch world!".to_vec()];
+        let filenames = vec!["greeting.txt".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::LZ10,
+            gfarch::GFCPOffset::Default
+        );
+
+        let files = gfarch::extract(&archive).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filename, "greeting.txt");
+        assert_eq!(files[0].contents, contents[0]);
+    }
+
+    #[test]
+    fn yaz0_round_trip() {
+        let contents = vec![b"abababababab gfarch yaz0 test data abababababab".to_vec()];
+        let filenames = vec!["stream.bin".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::Yaz0,
+            gfarch::GFCPOffset::Default
+        );
+
+        let files = gfarch::extract(&archive).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filename, "stream.bin");
+        assert_eq!(files[0].contents, contents[0]);
+    }
+
+    #[test]
+    fn yay0_round_trip() {
+        let contents = vec![b"abababababab gfarch yay0 test data abababababab".to_vec()];
+        let filenames = vec!["stream.bin".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::Yay0,
+            gfarch::GFCPOffset::Default
+        );
+
+        let files = gfarch::extract(&archive).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filename, "stream.bin");
+        assert_eq!(files[0].contents, contents[0]);
+    }
+
+    #[test]
+    fn extract_verified_detects_checksum_mismatch() {
+        let contents = vec![b"checksum me please".to_vec()];
+        let filenames = vec!["checked.txt".to_string()];
+
+        let mut archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::LZ10,
+            gfarch::GFCPOffset::Default
+        );
+
+        // corrupt the first file entry's checksum, at offset 0x30
+        archive[0x30..0x34].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+
+        match gfarch::extract_verified(&archive) {
+            Err(gfarch::GfArchError::IntegrityError { filename, .. }) => {
+                assert_eq!(filename, "checked.txt");
+            },
+            other => panic!("expected IntegrityError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn extract_returns_err_on_truncated_archive() {
+        let contents = vec![b"this archive will be cut short".to_vec()];
+        let filenames = vec!["short.txt".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::LZ10,
+            gfarch::GFCPOffset::Default
+        );
+
+        let truncated = &archive[..archive.len() / 2];
+
+        assert!(gfarch::extract(truncated).is_err());
+    }
+
+    #[test]
+    fn extract_returns_err_on_truncated_bpe_archive() {
+        let contents = vec![b"this bpe archive will be cut short".to_vec()];
+        let filenames = vec!["short.txt".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::BPE,
+            gfarch::GFCPOffset::Default
+        );
+
+        let truncated = &archive[..archive.len() / 2];
+
+        assert!(gfarch::extract(truncated).is_err());
+
+        // bpe_rs::bpe::decode panics on some truncation lengths rather than
+        // others, so sweep every length to make sure extract never panics.
+        for len in 0..archive.len() {
+            let _ = gfarch::extract(&archive[..len]);
+        }
+    }
+
+    #[test]
+    fn extract_to_dir_round_trip() {
+        let contents = vec![b"streamed straight to disk".to_vec()];
+        let filenames = vec!["nested/streamed.txt".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::LZ10,
+            gfarch::GFCPOffset::Default
+        );
+
+        let out_dir = std::env::temp_dir().join(format!(
+            "gfarch_extract_to_dir_round_trip_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&out_dir).unwrap();
+
+        gfarch::extract_to_dir(&archive, &out_dir).unwrap();
+
+        let extracted = std::fs::read(out_dir.join("nested/streamed.txt")).unwrap();
+        assert_eq!(extracted, contents[0]);
+
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn multibyte_filename_round_trip() {
+        let contents = vec![b"utf8 filenames should survive a round trip".to_vec()];
+        let filenames = vec!["海亀_01.brres".to_string()];
+
+        let archive = gfarch::pack_from_bytes(
+            &contents,
+            &filenames,
+            gfarch::Version::V3,
+            gfarch::CompressionType::LZ10,
+            gfarch::GFCPOffset::Default
+        );
+
+        let files = gfarch::extract(&archive).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filename, "海亀_01.brres");
+        assert_eq!(files[0].contents, contents[0]);
+    }
 }